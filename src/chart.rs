@@ -1,5 +1,7 @@
 use std::fmt::Debug;
 
+use plotters::chart::DualCoordChartContext;
+use plotters::coord::cartesian::Cartesian3d;
 use plotters::coord::ranged1d::AsRangedCoord;
 use plotters::coord::Shift;
 use plotters::prelude::*;
@@ -10,6 +12,9 @@ const INDEX_BOTTOM: usize = 1;
 const INDEX_LEFT: usize = 2;
 const INDEX_RIGHT: usize = 3;
 
+/// Extra padding added to a rendered tick label's measured extent in [`ChartLayout::auto_label_area_sizes`].
+const AUTO_LABEL_AREA_PADDING: u32 = 10;
+
 type DrawingResult<T, DB> = Result<T, DrawingAreaErrorKind<<DB as DrawingBackend>::ErrorType>>;
 
 type ChartContext2d<'a, DB, X, Y> = ChartContext<
@@ -18,13 +23,105 @@ type ChartContext2d<'a, DB, X, Y> = ChartContext<
     Cartesian2d<<X as AsRangedCoord>::CoordDescType, <Y as AsRangedCoord>::CoordDescType>,
 >;
 
+type DualCoordChartContext2d<'a, DB, X1, Y1, X2, Y2> = DualCoordChartContext<
+    'a,
+    DB,
+    Cartesian2d<<X1 as AsRangedCoord>::CoordDescType, <Y1 as AsRangedCoord>::CoordDescType>,
+    Cartesian2d<<X2 as AsRangedCoord>::CoordDescType, <Y2 as AsRangedCoord>::CoordDescType>,
+>;
+
+type ChartContext3d<'a, DB, X, Y, Z> = ChartContext<
+    'a,
+    DB,
+    Cartesian3d<
+        <X as AsRangedCoord>::CoordDescType,
+        <Y as AsRangedCoord>::CoordDescType,
+        <Z as AsRangedCoord>::CoordDescType,
+    >,
+>;
+
+/// A length expressed either as an absolute pixel count or as a percentage of the relevant
+/// axis of a reference dimension.
+///
+/// Every margin and label-area setter on [`ChartLayout`] accepts `impl Into<Size>`, so plain
+/// `u32` pixel values keep working unchanged. Percentages are resolved when
+/// [`bind()`](ChartLayout::bind) is called: the caption height against the root area's height,
+/// and margins and label areas against the area left below the caption.
+/// [`desired_image_size`](ChartLayout::desired_image_size) and
+/// [`desired_image_height_from_width`](ChartLayout::desired_image_height_from_width), which run
+/// before a root area exists, resolve them the same way against an explicit reference size.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Size {
+    /// An absolute size in pixels.
+    Pixel(u32),
+    /// A percentage (`0.0..=100.0`) of the relevant axis of the reference dimensions.
+    Percent(f64),
+}
+
+impl Size {
+    fn resolve(self, reference: u32) -> u32 {
+        match self {
+            Size::Pixel(px) => px,
+            Size::Percent(pct) => {
+                let pct = pct.clamp(0.0, 100.0);
+                (reference as f64 * pct / 100.0).round() as u32
+            }
+        }
+    }
+}
+
+impl From<u32> for Size {
+    fn from(pixels: u32) -> Self {
+        Size::Pixel(pixels)
+    }
+}
+
+impl From<f64> for Size {
+    fn from(percent: f64) -> Self {
+        Size::Percent(percent)
+    }
+}
+
+fn resolve_sizes(sizes: [Size; 4], reference: (u32, u32)) -> [u32; 4] {
+    [
+        sizes[INDEX_TOP].resolve(reference.1),
+        sizes[INDEX_BOTTOM].resolve(reference.1),
+        sizes[INDEX_LEFT].resolve(reference.0),
+        sizes[INDEX_RIGHT].resolve(reference.0),
+    ]
+}
+
+/// The configured margins and label areas don't fit within the available area.
+///
+/// This can happen even with pixel-only sizes, but is most easily triggered by
+/// percentage-based [`Size`]s that sum close to (or over) 100% of the reference dimension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LayoutOverflowError {
+    /// Total margin/label-area size requested along each axis, `(width, height)`.
+    pub requested: (u32, u32),
+    /// Size of the area the above was requested to fit within, `(width, height)`.
+    pub available: (u32, u32),
+}
+
+impl std::fmt::Display for LayoutOverflowError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "margins and label areas {:?} do not fit within the available area {:?}",
+            self.requested, self.available
+        )
+    }
+}
+
+impl std::error::Error for LayoutOverflowError {}
+
 /// Specifies layout of chart before creating [`DrawingArea`]
 #[derive(Clone)]
 pub struct ChartLayout<'a> {
-    title_height: u32,
+    title_height: Size,
     title_content: Option<(String, TextStyle<'a>, u32)>,
-    margin: [u32; 4],
-    label_area_size: [u32; 4],
+    margin: [Size; 4],
+    label_area_size: [Size; 4],
 }
 
 impl<'a> Debug for ChartLayout<'a> {
@@ -52,77 +149,84 @@ fn estimate_text_size(text: &str, font: &FontDesc) -> Result<(u32, u32), FontErr
 impl<'a> ChartLayout<'a> {
     pub fn new() -> Self {
         Self {
-            label_area_size: [0; 4],
-            title_height: 0,
+            label_area_size: [Size::Pixel(0); 4],
+            title_height: Size::Pixel(0),
             title_content: None,
-            margin: [0; 4],
+            margin: [Size::Pixel(0); 4],
         }
     }
 
     pub fn set_all_label_area_size(
         &mut self,
-        top: u32,
-        bottom: u32,
-        left: u32,
-        right: u32,
+        top: impl Into<Size>,
+        bottom: impl Into<Size>,
+        left: impl Into<Size>,
+        right: impl Into<Size>,
     ) -> &mut Self {
-        self.label_area_size = [top, bottom, left, right];
+        self.label_area_size = [top.into(), bottom.into(), left.into(), right.into()];
         self
     }
 
-    pub fn x_label_area_size(&mut self, size: u32) -> &mut Self {
-        self.label_area_size[INDEX_BOTTOM] = size;
+    pub fn x_label_area_size(&mut self, size: impl Into<Size>) -> &mut Self {
+        self.label_area_size[INDEX_BOTTOM] = size.into();
         self
     }
 
-    pub fn y_label_area_size(&mut self, size: u32) -> &mut Self {
-        self.label_area_size[INDEX_LEFT] = size;
+    pub fn y_label_area_size(&mut self, size: impl Into<Size>) -> &mut Self {
+        self.label_area_size[INDEX_LEFT] = size.into();
         self
     }
 
-    pub fn top_x_label_area_size(&mut self, size: u32) -> &mut Self {
-        self.label_area_size[INDEX_TOP] = size;
+    pub fn top_x_label_area_size(&mut self, size: impl Into<Size>) -> &mut Self {
+        self.label_area_size[INDEX_TOP] = size.into();
         self
     }
 
-    pub fn right_y_label_area_size(&mut self, size: u32) -> &mut Self {
-        self.label_area_size[INDEX_RIGHT] = size;
+    pub fn right_y_label_area_size(&mut self, size: impl Into<Size>) -> &mut Self {
+        self.label_area_size[INDEX_RIGHT] = size.into();
         self
     }
 
-    pub fn set_all_margin(&mut self, top: u32, bottom: u32, left: u32, right: u32) -> &mut Self {
-        self.margin = [top, bottom, left, right];
+    pub fn set_all_margin(
+        &mut self,
+        top: impl Into<Size>,
+        bottom: impl Into<Size>,
+        left: impl Into<Size>,
+        right: impl Into<Size>,
+    ) -> &mut Self {
+        self.margin = [top.into(), bottom.into(), left.into(), right.into()];
         self
     }
 
-    pub fn margin(&mut self, size: u32) -> &mut Self {
+    pub fn margin(&mut self, size: impl Into<Size>) -> &mut Self {
+        let size = size.into();
         self.margin = [size, size, size, size];
         self
     }
 
-    pub fn margin_top(&mut self, size: u32) -> &mut Self {
-        self.margin[INDEX_TOP] = size;
+    pub fn margin_top(&mut self, size: impl Into<Size>) -> &mut Self {
+        self.margin[INDEX_TOP] = size.into();
         self
     }
 
-    pub fn margin_bottom(&mut self, size: u32) -> &mut Self {
-        self.margin[INDEX_BOTTOM] = size;
+    pub fn margin_bottom(&mut self, size: impl Into<Size>) -> &mut Self {
+        self.margin[INDEX_BOTTOM] = size.into();
         self
     }
 
-    pub fn margin_left(&mut self, size: u32) -> &mut Self {
-        self.margin[INDEX_LEFT] = size;
+    pub fn margin_left(&mut self, size: impl Into<Size>) -> &mut Self {
+        self.margin[INDEX_LEFT] = size.into();
         self
     }
 
-    pub fn margin_right(&mut self, size: u32) -> &mut Self {
-        self.margin[INDEX_RIGHT] = size;
+    pub fn margin_right(&mut self, size: impl Into<Size>) -> &mut Self {
+        self.margin[INDEX_RIGHT] = size.into();
         self
     }
 
     // Clears caption text and area information for caption
     pub fn no_caption(&mut self) -> &mut Self {
-        self.title_height = 0;
+        self.title_height = Size::Pixel(0);
         self.title_content = None;
         self
     }
@@ -138,7 +242,7 @@ impl<'a> ChartLayout<'a> {
         let (_, text_h) = estimate_text_size(&text, &font)?;
         let style: TextStyle = font.into();
         let y_padding = (text_h / 2).min(5);
-        self.title_height = y_padding * 2 + text_h;
+        self.title_height = Size::Pixel(y_padding * 2 + text_h);
         self.title_content = Some((text, style, y_padding));
         Ok(self)
     }
@@ -154,27 +258,109 @@ impl<'a> ChartLayout<'a> {
         self
     }
 
-    fn additional_sizes(&self) -> (u32, u32) {
-        let [m_top, m_bottom, m_left, m_right] = self.margin;
-        let [l_top, l_bottom, l_left, l_right] = self.label_area_size;
+    /// Overrides the caption area height, e.g. to reserve a percentage of the image for it
+    /// instead of the size [`caption()`](Self::caption) computed from the rendered title text.
+    ///
+    /// Has no effect on the caption text itself; if the override is smaller than the text needs,
+    /// the text may be clipped.
+    pub fn caption_height(&mut self, size: impl Into<Size>) -> &mut Self {
+        self.title_height = size.into();
+        self
+    }
+
+    /// Sizes label areas from the tallest/widest rendered tick label.
+    ///
+    /// Measures `x_labels` with `label_font` and sets the bottom label area to the tallest
+    /// rendered height, and measures `y_labels` to set the left label area to the widest
+    /// rendered width, each plus a small padding. This avoids hand-tuning
+    /// [`x_label_area_size`](Self::x_label_area_size)/[`y_label_area_size`](Self::y_label_area_size)
+    /// pixel guesses, which is the most common source of clipped axis labels.
+    ///
+    /// `top_labels`/`right_labels` are optional; when given, they size the top/right label
+    /// areas the same way.
+    pub fn auto_label_area_sizes(
+        &mut self,
+        x_labels: &[String],
+        y_labels: &[String],
+        top_labels: Option<&[String]>,
+        right_labels: Option<&[String]>,
+        label_font: impl Into<FontDesc<'a>>,
+    ) -> Result<&mut Self, FontError> {
+        let font: FontDesc = label_font.into();
+
+        self.auto_size_label_area(INDEX_BOTTOM, x_labels, &font, |(_, h)| h)?;
+        self.auto_size_label_area(INDEX_LEFT, y_labels, &font, |(w, _)| w)?;
+        if let Some(labels) = top_labels {
+            self.auto_size_label_area(INDEX_TOP, labels, &font, |(_, h)| h)?;
+        }
+        if let Some(labels) = right_labels {
+            self.auto_size_label_area(INDEX_RIGHT, labels, &font, |(w, _)| w)?;
+        }
+
+        Ok(self)
+    }
+
+    fn auto_size_label_area(
+        &mut self,
+        index: usize,
+        labels: &[String],
+        font: &FontDesc,
+        dimension: impl Fn((u32, u32)) -> u32,
+    ) -> Result<(), FontError> {
+        if let Some(size) = labels
+            .iter()
+            .map(|label| estimate_text_size(label, font))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .map(dimension)
+            .max()
+        {
+            self.label_area_size[index] = Size::Pixel(size + AUTO_LABEL_AREA_PADDING);
+        }
+        Ok(())
+    }
+
+    fn additional_sizes(&self, reference: (u32, u32)) -> (u32, u32) {
+        // Mirror bind(): the caption is resolved against the full reference height first, and
+        // margins/label areas are resolved against what's left, since that's the area they
+        // actually apply to once bound.
+        let title_height = self.title_height.resolve(reference.1);
+        let main_reference = (reference.0, reference.1.saturating_sub(title_height));
+        let [m_top, m_bottom, m_left, m_right] = resolve_sizes(self.margin, main_reference);
+        let [l_top, l_bottom, l_left, l_right] = resolve_sizes(self.label_area_size, main_reference);
         let width = m_left + m_right + l_left + l_right;
-        let height = self.title_height + m_top + m_bottom + l_top + l_bottom;
+        let height = title_height + m_top + m_bottom + l_top + l_bottom;
         (width, height)
     }
 
     /// Size of root area whose plotting area will be equal to `plot_size`.
     ///
     /// An [`DrawingArea`] with returned size should be given for [`bind()`](Self::bind).
-    pub fn desired_image_size(&self, plot_size: (u32, u32)) -> (u32, u32) {
-        let additional = self.additional_sizes();
+    ///
+    /// `reference_size` is used to resolve any percentage-based margins or label-area sizes,
+    /// since no root area exists yet at this point; pass the root area's intended size (often
+    /// the returned size itself, or `plot_size` when every margin/label area is pixel-only).
+    pub fn desired_image_size(
+        &self,
+        plot_size: (u32, u32),
+        reference_size: (u32, u32),
+    ) -> (u32, u32) {
+        let additional = self.additional_sizes(reference_size);
         (plot_size.0 + additional.0, plot_size.1 + additional.1)
     }
 
     /// Estimates required root-area height from its width and the aspect ratio of the plotting area.
     ///
-    /// `aspect_ratio` is the ratio of plotting-area height to its width.
-    pub fn desired_image_height_from_width(&self, image_width: u32, aspect_ratio: f64) -> u32 {
-        let additional = self.additional_sizes();
+    /// `aspect_ratio` is the ratio of plotting-area height to its width. `reference_size` is
+    /// used to resolve any percentage-based margins or label-area sizes, as in
+    /// [`desired_image_size`](Self::desired_image_size).
+    pub fn desired_image_height_from_width(
+        &self,
+        image_width: u32,
+        aspect_ratio: f64,
+        reference_size: (u32, u32),
+    ) -> u32 {
+        let additional = self.additional_sizes(reference_size);
         if image_width < additional.0 {
             additional.1
         } else {
@@ -183,17 +369,20 @@ impl<'a> ChartLayout<'a> {
     }
 
     /// Bind layout information to an actual root area.
-    pub fn bind<'b, DB>(
+    ///
+    /// The caption height is resolved against `root_area`'s dimensions and split off first;
+    /// percentage-based margins and label-area sizes are then resolved against what remains,
+    /// since that's the area they actually apply to.
+    pub fn bind<DB>(
         &self,
-        root_area: &'b DrawingArea<DB, Shift>,
-    ) -> DrawingResult<ChartLayoutBuilder<'b, DB>, DB>
+        root_area: &DrawingArea<DB, Shift>,
+    ) -> DrawingResult<ChartLayoutBuilder<DB>, DB>
     where
-        'a: 'b,
         DB: DrawingBackend,
     {
         use plotters::style::text_anchor::{HPos, Pos, VPos};
 
-        let title_area_height = self.title_height;
+        let title_area_height = self.title_height.resolve(root_area.dim_in_pixel().1);
         let main_area = if title_area_height > 0 {
             let (title_area, main_area) = root_area.split_vertically(title_area_height);
             if let Some((text, style, y_padding)) = &self.title_content {
@@ -208,8 +397,14 @@ impl<'a> ChartLayout<'a> {
         } else {
             root_area.clone()
         };
+
+        let reference = main_area.dim_in_pixel();
+        let margin = resolve_sizes(self.margin, reference);
+        let label_area_size = resolve_sizes(self.label_area_size, reference);
+
         Ok(ChartLayoutBuilder {
-            layout: self.clone(),
+            margin,
+            label_area_size,
             main_area,
         })
     }
@@ -221,23 +416,33 @@ impl<'a> Default for ChartLayout<'a> {
     }
 }
 
-pub struct ChartLayoutBuilder<'a, DB: DrawingBackend> {
-    layout: ChartLayout<'a>,
+pub struct ChartLayoutBuilder<DB: DrawingBackend> {
+    margin: [u32; 4],
+    label_area_size: [u32; 4],
     main_area: DrawingArea<DB, Shift>,
 }
 
-impl<'a, DB: DrawingBackend> ChartLayoutBuilder<'a, DB> {
+impl<DB: DrawingBackend> ChartLayoutBuilder<DB> {
     /// Estimates size of the plotting area in pixels.
     ///
     /// Can be used to determine plotting value range to pass to [`build_cartesian_2d`](Self::build_cartesian_2d).
-    pub fn estimate_plot_area_size(&self) -> (u32, u32) {
-        let [m_top, m_bottom, m_left, m_right] = self.layout.margin;
-        let [l_top, l_bottom, l_left, l_right] = self.layout.label_area_size;
+    ///
+    /// Returns [`LayoutOverflowError`] if the margins and label areas don't fit within the
+    /// bound area, which can happen when percentage-based [`Size`]s sum close to 100%.
+    pub fn estimate_plot_area_size(&self) -> Result<(u32, u32), LayoutOverflowError> {
+        let [m_top, m_bottom, m_left, m_right] = self.margin;
+        let [l_top, l_bottom, l_left, l_right] = self.label_area_size;
         // main_area does not include caption part
         let (image_width, image_height) = self.main_area.dim_in_pixel();
-        let plot_width = image_width - (m_left + m_right + l_left + l_right);
-        let plot_height = image_height - (m_top + m_bottom + l_top + l_bottom);
-        (plot_width, plot_height)
+        let additional_width = m_left + m_right + l_left + l_right;
+        let additional_height = m_top + m_bottom + l_top + l_bottom;
+        let overflow = || LayoutOverflowError {
+            requested: (additional_width, additional_height),
+            available: (image_width, image_height),
+        };
+        let plot_width = image_width.checked_sub(additional_width).ok_or_else(overflow)?;
+        let plot_height = image_height.checked_sub(additional_height).ok_or_else(overflow)?;
+        Ok((plot_width, plot_height))
     }
 
     pub fn build_cartesian_2d<X: AsRangedCoord, Y: AsRangedCoord>(
@@ -245,8 +450,8 @@ impl<'a, DB: DrawingBackend> ChartLayoutBuilder<'a, DB> {
         x_spec: X,
         y_spec: Y,
     ) -> DrawingResult<ChartContext2d<DB, X, Y>, DB> {
-        let [m_top, m_bottom, m_left, m_right] = self.layout.margin;
-        let [l_top, l_bottom, l_left, l_right] = self.layout.label_area_size;
+        let [m_top, m_bottom, m_left, m_right] = self.margin;
+        let [l_top, l_bottom, l_left, l_right] = self.label_area_size;
 
         let mut builder = ChartBuilder::on(&self.main_area);
 
@@ -262,6 +467,69 @@ impl<'a, DB: DrawingBackend> ChartLayoutBuilder<'a, DB> {
 
         builder.build_cartesian_2d(x_spec, y_spec)
     }
+
+    /// Builds a chart with a secondary (dual-coordinate) axis.
+    ///
+    /// The primary context is built exactly as in [`build_cartesian_2d`](Self::build_cartesian_2d),
+    /// reserving the right/top label areas for the secondary axis, then
+    /// [`set_secondary_coord`](ChartContext::set_secondary_coord) attaches `secondary_x`/`secondary_y`
+    /// to it.
+    pub fn build_cartesian_2d_with_secondary<X1, Y1, X2, Y2>(
+        &self,
+        primary_x: X1,
+        primary_y: Y1,
+        secondary_x: X2,
+        secondary_y: Y2,
+    ) -> DrawingResult<DualCoordChartContext2d<DB, X1, Y1, X2, Y2>, DB>
+    where
+        X1: AsRangedCoord,
+        Y1: AsRangedCoord,
+        X2: AsRangedCoord,
+        Y2: AsRangedCoord,
+    {
+        let chart = self.build_cartesian_2d(primary_x, primary_y)?;
+        Ok(chart.set_secondary_coord(secondary_x, secondary_y))
+    }
+
+    /// Estimates size of the 3D plotting area in pixels.
+    ///
+    /// Unlike [`estimate_plot_area_size`](Self::estimate_plot_area_size), a 3D chart has no
+    /// axis label gutters: it fills the full margin box, so only the margins are subtracted.
+    ///
+    /// Returns [`LayoutOverflowError`] if the margins don't fit within the bound area, which can
+    /// happen when percentage-based [`Size`]s sum close to 100%.
+    pub fn estimate_plot_area_size_3d(&self) -> Result<(u32, u32), LayoutOverflowError> {
+        let [m_top, m_bottom, m_left, m_right] = self.margin;
+        let (image_width, image_height) = self.main_area.dim_in_pixel();
+        let additional_width = m_left + m_right;
+        let additional_height = m_top + m_bottom;
+        let overflow = || LayoutOverflowError {
+            requested: (additional_width, additional_height),
+            available: (image_width, image_height),
+        };
+        let plot_width = image_width.checked_sub(additional_width).ok_or_else(overflow)?;
+        let plot_height = image_height.checked_sub(additional_height).ok_or_else(overflow)?;
+        Ok((plot_width, plot_height))
+    }
+
+    pub fn build_cartesian_3d<X: AsRangedCoord, Y: AsRangedCoord, Z: AsRangedCoord>(
+        &self,
+        x_spec: X,
+        y_spec: Y,
+        z_spec: Z,
+    ) -> DrawingResult<ChartContext3d<DB, X, Y, Z>, DB> {
+        let [m_top, m_bottom, m_left, m_right] = self.margin;
+
+        let mut builder = ChartBuilder::on(&self.main_area);
+
+        builder
+            .margin_top(m_top)
+            .margin_bottom(m_bottom)
+            .margin_left(m_left)
+            .margin_right(m_right);
+
+        builder.build_cartesian_3d(x_spec, y_spec, z_spec)
+    }
 }
 
 #[cfg(test)]
@@ -311,7 +579,7 @@ mod tests {
         x_spec: Range<f64>,
         y_spec: Range<f64>,
     ) -> Result<(), Box<dyn Error>> {
-        let image_size = layout.desired_image_size(plot_size);
+        let image_size = layout.desired_image_size(plot_size, plot_size);
 
         let mut buf = vec![0u8; (3 * image_size.0 * image_size.1) as usize];
         let backend: BitMapBackend<RGBPixel> =
@@ -319,7 +587,7 @@ mod tests {
         let root_area = backend.into_drawing_area();
 
         let builder = layout.bind(&root_area)?;
-        let estimated_plot_size = builder.estimate_plot_area_size();
+        let estimated_plot_size = builder.estimate_plot_area_size()?;
         assert_eq!(
             plot_size, estimated_plot_size,
             "wrong estimation; layout = {layout:?}, image_size = {image_size:?}"
@@ -334,4 +602,168 @@ mod tests {
         );
         Ok(())
     }
+
+    fn drawing_area_of_size(
+        size: (u32, u32),
+        buf: &mut Vec<u8>,
+    ) -> Result<DrawingArea<BitMapBackend<RGBPixel>, plotters::coord::Shift>, Box<dyn Error>> {
+        *buf = vec![0u8; (3 * size.0 * size.1) as usize];
+        let backend: BitMapBackend<RGBPixel> = BitMapBackend::with_buffer_and_format(buf, size)?;
+        Ok(backend.into_drawing_area())
+    }
+
+    #[test]
+    fn cartesian_3d_size_estimation() -> Result<(), Box<dyn Error>> {
+        let plot_size = (200, 150);
+        let mut layout = ChartLayout::new();
+        layout.margin(5u32);
+
+        let image_size = layout.desired_image_size(plot_size, plot_size);
+        let mut buf = Vec::new();
+        let root_area = drawing_area_of_size(image_size, &mut buf)?;
+
+        let builder = layout.bind(&root_area)?;
+        let estimated_plot_size = builder.estimate_plot_area_size_3d()?;
+        assert_eq!(plot_size, estimated_plot_size);
+
+        let chart = builder.build_cartesian_3d(0f64..1f64, 0f64..1f64, 0f64..1f64)?;
+        let actual_size = chart.plotting_area().dim_in_pixel();
+        assert_eq!(plot_size, actual_size);
+
+        Ok(())
+    }
+
+    #[test]
+    fn cartesian_2d_with_secondary_axis() -> Result<(), Box<dyn Error>> {
+        let plot_size = (200, 150);
+        let mut layout = ChartLayout::new();
+        layout
+            .margin(5u32)
+            .x_label_area_size(20u32)
+            .y_label_area_size(20u32)
+            .right_y_label_area_size(20u32);
+
+        let image_size = layout.desired_image_size(plot_size, plot_size);
+        let mut buf = Vec::new();
+        let root_area = drawing_area_of_size(image_size, &mut buf)?;
+
+        let builder = layout.bind(&root_area)?;
+        let chart =
+            builder.build_cartesian_2d_with_secondary(0f64..1f64, 0f64..1f64, 0f64..2f64, 0f64..2f64)?;
+        let actual_size = chart.plotting_area().dim_in_pixel();
+        assert_eq!(plot_size, actual_size);
+
+        Ok(())
+    }
+
+    #[test]
+    fn auto_label_area_sizes_matches_widest_tallest_label() -> Result<(), Box<dyn Error>> {
+        let font: FontDesc = ("sans-serif", 20).into();
+        let x_labels = vec!["0".to_string(), "100".to_string()];
+        let y_labels = vec!["0".to_string(), "1000".to_string()];
+        let top_labels = vec!["lone top label".to_string()];
+
+        let mut layout = ChartLayout::new();
+        layout.auto_label_area_sizes(
+            &x_labels,
+            &y_labels,
+            Some(&top_labels),
+            None,
+            ("sans-serif", 20),
+        )?;
+
+        let (_, expected_bottom) = super::estimate_text_size("100", &font)?;
+        let (expected_left, _) = super::estimate_text_size("1000", &font)?;
+        let (_, expected_top) = super::estimate_text_size("lone top label", &font)?;
+
+        let plot_size = (200, 150);
+        let image_size = layout.desired_image_size(plot_size, plot_size);
+        let mut buf = Vec::new();
+        let root_area = drawing_area_of_size(image_size, &mut buf)?;
+
+        let builder = layout.bind(&root_area)?;
+        let estimated_plot_size = builder.estimate_plot_area_size()?;
+
+        assert_eq!(
+            image_size.0 - estimated_plot_size.0,
+            expected_left + super::AUTO_LABEL_AREA_PADDING,
+        );
+        assert_eq!(
+            image_size.1 - estimated_plot_size.1,
+            expected_bottom + expected_top + 2 * super::AUTO_LABEL_AREA_PADDING,
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn percent_size_resolve_rounds_and_clamps() {
+        assert_eq!(super::Size::Pixel(12).resolve(1000), 12);
+        assert_eq!(super::Size::Percent(25.0).resolve(200), 50);
+        assert_eq!(super::Size::Percent(33.0).resolve(10), 3);
+        assert_eq!(super::Size::Percent(150.0).resolve(100), 100);
+        assert_eq!(super::Size::Percent(-20.0).resolve(100), 0);
+    }
+
+    #[test]
+    fn percent_margin_resolves_against_post_caption_area() -> Result<(), Box<dyn Error>> {
+        let mut layout = ChartLayout::new();
+        // No caption text is drawn, but the reserved area still shrinks what margins/label
+        // areas resolve percentages against, exactly as a rendered caption would.
+        layout.caption_height(40u32);
+        layout.margin_top(50.0);
+
+        let root_size = (100u32, 200u32);
+        let mut buf = Vec::new();
+        let root_area = drawing_area_of_size(root_size, &mut buf)?;
+
+        let builder = layout.bind(&root_area)?;
+        let (_, plot_height) = builder.estimate_plot_area_size()?;
+
+        // main_area height = 200 - 40 = 160; 50% of that is 80, leaving 80 for the plot area.
+        // Resolving against the full 200px root height would have produced 60 instead.
+        assert_eq!(plot_height, 80);
+
+        Ok(())
+    }
+
+    #[test]
+    fn desired_image_size_agrees_with_bind_for_caption_plus_percent_margin() -> Result<(), Box<dyn Error>> {
+        let plot_size = (100u32, 100u32);
+        let mut layout = ChartLayout::new();
+        layout.caption_height(40u32);
+        layout.margin_top(50.0);
+
+        // Percentage sizes are resolved against `reference_size`, so a self-consistent caller
+        // passes the actual root-area size it intends to bind against; this is the fixed point
+        // where plot_size + additional_sizes(reference_size) == reference_size.
+        let reference_size = (100u32, 240u32);
+        let image_size = layout.desired_image_size(plot_size, reference_size);
+        assert_eq!(image_size, reference_size);
+
+        let mut buf = Vec::new();
+        let root_area = drawing_area_of_size(image_size, &mut buf)?;
+
+        let builder = layout.bind(&root_area)?;
+        let estimated_plot_size = builder.estimate_plot_area_size()?;
+
+        assert_eq!(plot_size, estimated_plot_size);
+
+        Ok(())
+    }
+
+    #[test]
+    fn overflowing_margins_return_error_instead_of_panicking() -> Result<(), Box<dyn Error>> {
+        let mut layout = ChartLayout::new();
+        layout.margin(1000u32);
+
+        let root_size = (100u32, 100u32);
+        let mut buf = Vec::new();
+        let root_area = drawing_area_of_size(root_size, &mut buf)?;
+
+        let builder = layout.bind(&root_area)?;
+        assert!(builder.estimate_plot_area_size().is_err());
+
+        Ok(())
+    }
 }
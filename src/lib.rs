@@ -14,7 +14,7 @@
 //!     .margin(4)
 //!     .x_label_area_size(40)
 //!     .y_label_area_size(40);
-//! let (w, h): (u32, u32) = layout.desired_image_size((200, 160));
+//! let (w, h): (u32, u32) = layout.desired_image_size((200, 160), (200, 160));
 //! let mut buf = vec![0u8; (w * h) as usize * RGBPixel::PIXEL_SIZE];
 //! let graph = BitMapBackend::with_buffer(&mut buf, (w, h));
 //! let root_area = graph.into_drawing_area();
@@ -43,7 +43,7 @@
 //!     .y_label_area_size(40)
 //!     .bind(&root_area)?;
 //!
-//! let (width, height) = builder.estimate_plot_area_size();
+//! let (width, height) = builder.estimate_plot_area_size()?;
 //! let (x_range, y_range) = centering_ranges(&min_range, &(width as f64, height as f64));
 //!
 //! // (x_range, y_range) and (width, height) has same aspect ratio
@@ -63,9 +63,41 @@ use std::ops::{Add, Div, Mul, Range, Sub};
 
 pub use crate::chart::*;
 
-pub fn centering_ranges<T, S>(
+/// Selects where the expanded axis is positioned within the destination aspect ratio in
+/// [`fit_ranges`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Anchor {
+    /// Anchor the expanded axis to the start of the original range.
+    Start,
+    /// Center the expanded axis on the original range's midpoint (the [`centering_ranges`] behavior).
+    Center,
+    /// Anchor the expanded axis to the end of the original range.
+    End,
+}
+
+fn anchored_range<T>(start: T, end: T, radius: T, half: T, anchor: Anchor) -> Range<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    match anchor {
+        Anchor::Start => start..(start + radius + radius),
+        Anchor::Center => {
+            let center = (start + end) * half;
+            (center - radius)..(radius + center)
+        }
+        Anchor::End => (end - radius - radius)..end,
+    }
+}
+
+/// Expands the axis of `minimum` that is too short for `destination`'s aspect ratio so the two
+/// ratios match, positioning the expanded axis according to `anchor`.
+///
+/// The returned ranges always contain `minimum` and exactly match the aspect ratio of
+/// `destination`.
+pub fn fit_ranges<T, S>(
     minimum: &(Range<T>, Range<T>),
     destination: &(S, S),
+    anchor: Anchor,
 ) -> (Range<T>, Range<T>)
 where
     T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
@@ -79,14 +111,83 @@ where
     if sx * dy < sy * dx {
         // sx -> sy * dx / dy
         let radius = sy * dx / dy * half;
-        let center = (minimum.0.start + minimum.0.end) * half;
-        let s0 = (center - radius)..(radius + center);
+        let s0 = anchored_range(minimum.0.start, minimum.0.end, radius, half, anchor);
         (s0, minimum.1.clone())
     } else {
         // sy -> sx * dy / dx
         let radius = sx * dy / dx * half;
-        let center = (minimum.1.end + minimum.1.start) * half;
-        let s1 = (center - radius)..(radius + center);
+        let s1 = anchored_range(minimum.1.start, minimum.1.end, radius, half, anchor);
         (minimum.0.clone(), s1)
     }
 }
+
+/// Centers `minimum` within `destination`'s aspect ratio by expanding the axis that is too short
+/// for it.
+///
+/// Thin wrapper over [`fit_ranges`] with [`Anchor::Center`].
+pub fn centering_ranges<T, S>(
+    minimum: &(Range<T>, Range<T>),
+    destination: &(S, S),
+) -> (Range<T>, Range<T>)
+where
+    T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+    S: Copy + Into<T>,
+{
+    fit_ranges(minimum, destination, Anchor::Center)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{centering_ranges, fit_ranges, Anchor};
+
+    #[test]
+    fn fit_ranges_contains_minimum_and_matches_aspect_ratio() {
+        // sx = 100, sy = 20: the y axis is the one that needs expanding for a square destination.
+        let minimum = (-50f64..50f64, -10f64..10f64);
+        let destination = (100f64, 100f64);
+
+        for anchor in [Anchor::Start, Anchor::Center, Anchor::End] {
+            let (x_range, y_range) = fit_ranges(&minimum, &destination, anchor);
+            assert_eq!(x_range, minimum.0, "anchor = {anchor:?}");
+            assert!(
+                y_range.start <= minimum.1.start && y_range.end >= minimum.1.end,
+                "anchor = {anchor:?}, y_range = {y_range:?} does not contain minimum {:?}",
+                minimum.1
+            );
+
+            let inner_ratio = (x_range.end - x_range.start) / (y_range.end - y_range.start);
+            let outer_ratio = destination.0 / destination.1;
+            assert!(
+                (inner_ratio - outer_ratio).abs() < 1e-8,
+                "anchor = {anchor:?}, inner_ratio = {inner_ratio}, outer_ratio = {outer_ratio}"
+            );
+        }
+    }
+
+    #[test]
+    fn fit_ranges_anchors_position_the_expanded_axis() {
+        let minimum = (-50f64..50f64, -10f64..10f64);
+        let destination = (100f64, 100f64);
+
+        let (_, y_start) = fit_ranges(&minimum, &destination, Anchor::Start);
+        assert!((y_start.start - minimum.1.start).abs() < 1e-8);
+
+        let (_, y_end) = fit_ranges(&minimum, &destination, Anchor::End);
+        assert!((y_end.end - minimum.1.end).abs() < 1e-8);
+
+        let (_, y_center) = fit_ranges(&minimum, &destination, Anchor::Center);
+        let center_of_minimum = (minimum.1.start + minimum.1.end) * 0.5;
+        let center_of_expanded = (y_center.start + y_center.end) * 0.5;
+        assert!((center_of_minimum - center_of_expanded).abs() < 1e-8);
+    }
+
+    #[test]
+    fn centering_ranges_matches_fit_ranges_with_center_anchor() {
+        let minimum = (-50f64..50f64, -10f64..10f64);
+        let destination = (100f64, 100f64);
+        assert_eq!(
+            centering_ranges(&minimum, &destination),
+            fit_ranges(&minimum, &destination, Anchor::Center)
+        );
+    }
+}